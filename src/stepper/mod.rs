@@ -1,4 +1,4 @@
-//! Implements a number stepper. By default this uses NSStepper on macOS.
+//! Implements a number stepper. By default this uses NSStepper on macOS, and UIStepper on iOS.
 
 use core_graphics::geometry::CGRect;
 use objc::rc::{Id, Shared};
@@ -15,7 +15,7 @@ use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 use crate::objc_access::ObjcAccess;
 use crate::utils::properties::ObjcProperty;
 
-/// Wraps `NSStepper` on AppKit. Not currently implemented for iOS.
+/// Wraps `NSStepper` on AppKit, and `UIStepper` on UIKit.
 #[derive(Debug)]
 pub struct Stepper {
     /// A handle for the underlying Objective-C object.
@@ -125,9 +125,28 @@ impl Stepper {
         self.handler = Some(handler);
     }
 
+    /// Attaches a callback that's handed the stepper's current value directly, rather than the
+    /// raw sender - saving callers from having to read the value (`doubleValue` on AppKit,
+    /// `value` on UIKit) back off the stepper themselves.
+    pub fn set_value_action<F: Fn(f64) + Send + Sync + 'static>(&mut self, action: F) {
+        self.set_action(move |obj| {
+            #[cfg(feature = "uikit")]
+            let value: f64 = unsafe { msg_send![obj, value] };
+
+            #[cfg(not(feature = "uikit"))]
+            let value: f64 = unsafe { msg_send![obj, doubleValue] };
+
+            action(value);
+        });
+    }
+
     /// Sets maximum value
     pub fn set_max_value(&self, value: f64) {
         self.objc.with_mut(|obj| unsafe {
+            #[cfg(feature = "uikit")]
+            let _: () = msg_send![obj, setMaximumValue: value];
+
+            #[cfg(not(feature = "uikit"))]
             let _: () = msg_send![obj, setMaxValue: value];
         });
     }
@@ -135,6 +154,10 @@ impl Stepper {
     /// Sets minimum value
     pub fn set_min_value(&self, value: f64) {
         self.objc.with_mut(|obj| unsafe {
+            #[cfg(feature = "uikit")]
+            let _: () = msg_send![obj, setMinimumValue: value];
+
+            #[cfg(not(feature = "uikit"))]
             let _: () = msg_send![obj, setMinValue: value];
         });
     }
@@ -142,6 +165,10 @@ impl Stepper {
     /// Sets increment
     pub fn set_increment(&self, value: f64) {
         self.objc.with_mut(|obj| unsafe {
+            #[cfg(feature = "uikit")]
+            let _: () = msg_send![obj, setStepValue: value];
+
+            #[cfg(not(feature = "uikit"))]
             let _: () = msg_send![obj, setIncrement: value];
         });
     }
@@ -149,6 +176,13 @@ impl Stepper {
     /// Sets whether this wraps
     pub fn set_wraps(&self, wraps: bool) {
         self.objc.with_mut(|obj| unsafe {
+            #[cfg(feature = "uikit")]
+            let _: () = msg_send![obj, setWraps:match wraps {
+                true => YES,
+                false => NO
+            }];
+
+            #[cfg(not(feature = "uikit"))]
             let _: () = msg_send![obj, setValueWraps:match wraps {
                 true => YES,
                 false => NO
@@ -156,9 +190,63 @@ impl Stepper {
         });
     }
 
-    /// Gets the selected index.
+    /// Sets the current value.
+    pub fn set_value(&self, value: f64) {
+        self.objc.with_mut(|obj| unsafe {
+            #[cfg(feature = "uikit")]
+            let _: () = msg_send![obj, setValue: value];
+
+            #[cfg(not(feature = "uikit"))]
+            let _: () = msg_send![obj, setDoubleValue: value];
+        });
+    }
+
+    /// Sets whether this stepper repeatedly fires its action while the control is held down.
+    pub fn set_autorepeat(&self, autorepeat: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setAutorepeat:match autorepeat {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
+    /// Sets whether this stepper fires its action continuously as the value changes, rather than
+    /// only once the control is released.
+    pub fn set_continuous(&self, continuous: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setContinuous:match continuous {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
+    /// Gets the current value as a float.
     pub fn get_selected_value(&self) -> f32 {
-        self.objc.get(|obj| unsafe { msg_send![obj, floatValue] })
+        #[cfg(feature = "uikit")]
+        return self.get_value() as f32;
+
+        #[cfg(not(feature = "uikit"))]
+        return self.objc.get(|obj| unsafe { msg_send![obj, floatValue] });
+    }
+
+    /// Gets the current value as a double.
+    pub fn get_value(&self) -> f64 {
+        #[cfg(feature = "uikit")]
+        return self.objc.get(|obj| unsafe { msg_send![obj, value] });
+
+        #[cfg(not(feature = "uikit"))]
+        return self.objc.get(|obj| unsafe { msg_send![obj, doubleValue] });
+    }
+
+    /// Gets the current value as an integer.
+    pub fn get_integer_value(&self) -> NSInteger {
+        #[cfg(feature = "uikit")]
+        return self.get_value() as NSInteger;
+
+        #[cfg(not(feature = "uikit"))]
+        return self.objc.get(|obj| unsafe { msg_send![obj, integerValue] });
     }
 }
 
@@ -214,14 +302,27 @@ impl Drop for Stepper {
     // but I'd rather be paranoid and remove them later.
     fn drop(&mut self) {
         self.objc.with_mut(|obj| unsafe {
-            let _: () = msg_send![obj, setTarget: nil];
-            let _: () = msg_send![obj, setAction: nil];
+            // UIControlEventAllEvents
+            #[cfg(feature = "uikit")]
+            let _: () = msg_send![obj, removeTarget:nil action:nil forControlEvents:0xFFFFFFFFu64];
+
+            #[cfg(not(feature = "uikit"))]
+            {
+                let _: () = msg_send![obj, setTarget: nil];
+                let _: () = msg_send![obj, setAction: nil];
+            }
         });
     }
 }
 
-/// Registers an `NSStepper` subclass, and configures it to hold some ivars
-/// for various things we need to store.
+/// Registers a `UIStepper` subclass. No ivars are added to it.
+#[cfg(feature = "uikit")]
+fn register_class() -> &'static Class {
+    load_or_register_class("UIStepper", "CacaoStepper", |decl| unsafe {})
+}
+
+/// Registers an `NSStepper` subclass. No ivars are added to it.
+#[cfg(not(feature = "uikit"))]
 fn register_class() -> &'static Class {
     load_or_register_class("NSStepper", "CacaoStepper", |decl| unsafe {})
 }